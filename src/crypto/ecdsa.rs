@@ -0,0 +1,79 @@
+use openssl::bn::BigNum;
+use openssl::ec::EcKey;
+use openssl::ecdsa::EcdsaSig;
+use openssl::hash::MessageDigest;
+use openssl::pkey::{PKey, Public};
+use openssl::sign::{Signer, Verifier};
+
+use crate::errors::{new_error, ErrorKind, Result};
+use crate::serialization::{b64_decode, b64_encode};
+use crate::Algorithm;
+
+/// Returns the digest and the raw `r`/`s` component size (in bytes) for a given EC `Algorithm`:
+/// ES256 pairs P-256 with SHA-256, ES384 pairs P-384 with SHA-384, and ES512 pairs P-521 with
+/// SHA-512.
+fn alg_to_ec_signing(algorithm: Algorithm) -> (MessageDigest, usize) {
+    match algorithm {
+        Algorithm::ES256 => (MessageDigest::sha256(), 32),
+        Algorithm::ES384 => (MessageDigest::sha384(), 48),
+        Algorithm::ES512 => (MessageDigest::sha512(), 66),
+        _ => unreachable!("alg_to_ec_signing called with a non-EC algorithm"),
+    }
+}
+
+/// Converts an ASN.1 DER-encoded ECDSA signature (as produced by `openssl`) into the
+/// fixed-length raw `r || s` form JWS requires, left-padding each half to `component_size`.
+fn der_to_raw(der: &[u8], component_size: usize) -> Result<Vec<u8>> {
+    let sig = EcdsaSig::from_der(der).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+    let mut raw = Vec::with_capacity(component_size * 2);
+    for component in [sig.r(), sig.s()] {
+        let bytes = component.to_vec();
+        if bytes.len() > component_size {
+            return Err(new_error(ErrorKind::InvalidEcdsaKey));
+        }
+        raw.extend(std::iter::repeat_n(0u8, component_size - bytes.len()));
+        raw.extend_from_slice(&bytes);
+    }
+    Ok(raw)
+}
+
+/// Converts a raw `r || s` JWS signature back into ASN.1 DER, the form `openssl`'s verifier
+/// expects.
+fn raw_to_der(raw: &[u8], component_size: usize) -> Result<Vec<u8>> {
+    if raw.len() != component_size * 2 {
+        return Err(new_error(ErrorKind::InvalidSignature));
+    }
+    let r = BigNum::from_slice(&raw[..component_size]).map_err(|_| new_error(ErrorKind::InvalidSignature))?;
+    let s = BigNum::from_slice(&raw[component_size..]).map_err(|_| new_error(ErrorKind::InvalidSignature))?;
+    EcdsaSig::from_private_components(r, s)
+        .and_then(|sig| sig.to_der())
+        .map_err(|_| new_error(ErrorKind::InvalidSignature))
+}
+
+/// Signs `message` with a PKCS#8 DER-encoded EC private key, returning the base64url-encoded
+/// raw `r || s` JWS signature (not the DER form `openssl` produces by default).
+pub(crate) fn sign_pkcs8(algorithm: Algorithm, key_pkcs8: &[u8], message: &str) -> Result<String> {
+    let (digest, component_size) = alg_to_ec_signing(algorithm);
+    let pkey = PKey::private_key_from_pkcs8(key_pkcs8).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+
+    let mut signer = Signer::new(digest, &pkey).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+    signer.update(message.as_bytes()).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+    let der_sig = signer.sign_to_vec().map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+
+    der_to_raw(&der_sig, component_size).map(|raw| b64_encode(&raw))
+}
+
+/// Verifies a raw `r || s` JWS signature against an EC public key.
+pub(crate) fn verify(algorithm: Algorithm, signature: &str, message: &str, key: &EcKey<Public>) -> Result<bool> {
+    let (digest, component_size) = alg_to_ec_signing(algorithm);
+    let raw_sig = b64_decode(signature).map_err(|_| new_error(ErrorKind::InvalidSignature))?;
+    let der_sig = match raw_to_der(&raw_sig, component_size) {
+        Ok(der) => der,
+        Err(_) => return Ok(false),
+    };
+
+    let pkey = PKey::from_ec_key(key.clone()).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+    let mut verifier = Verifier::new(digest, &pkey).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+    verifier.update(message.as_bytes()).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))?;
+    Ok(verifier.verify(&der_sig).unwrap_or(false))
+}
@@ -7,17 +7,40 @@ use crate::serialization::{b64_decode, b64_encode};
 use crate::Algorithm;
 
 use sha2::{Sha256, Sha384, Sha512};
-// pub(crate) mod ecdsa;
+pub(crate) mod ecdsa;
 pub(crate) mod rsa;
 
 type HmacSha256 = Hmac<Sha256>;
 type HmacSha384 = Hmac<Sha384>;
 type HmacSha512 = Hmac<Sha512>;
+
+/// The minimum HMAC key length (in bytes) RFC 7518 §3.2 requires for a given HS* algorithm:
+/// the key must be at least as long as the hash output.
+fn min_hmac_key_len(alg: Algorithm) -> usize {
+    match alg {
+        Algorithm::HS256 => 32,
+        Algorithm::HS384 => 48,
+        Algorithm::HS512 => 64,
+        _ => unreachable!("min_hmac_key_len called with a non-HMAC algorithm"),
+    }
+}
+
+/// Rejects HMAC keys shorter than RFC 7518 §3.2 allows, instead of silently letting a
+/// dangerously weak secret through.
+fn check_hmac_key_len(alg: Algorithm, key: &[u8]) -> Result<()> {
+    if key.len() < min_hmac_key_len(alg) {
+        return Err(new_error(ErrorKind::InvalidKeyLength));
+    }
+    Ok(())
+}
+
 /// The actual HS signing + encoding
 /// Could be in its own file to match RSA/EC but it's 2 lines...
 pub(crate) fn sign_hmac(alg: Algorithm, key: &[u8], message: &str) -> Result<String> {
     // println!("alg: {:?}\nkey: {:?}\nmessage: {:?}");
 
+    check_hmac_key_len(alg, key)?;
+
     // let digest = hmac::sign(&hmac::Key::new(alg, key), message.as_bytes());
     let digest = match alg {
         Algorithm::HS256 => {
@@ -47,10 +70,8 @@ pub fn validate_matching_key(key: &EncodingKey, algorithm: Algorithm) -> Result<
             Algorithm::None => Ok(()),
             _ => Err(ErrorKind::InvalidAlgorithm.into()),
         },
-        EncodingKey::OctetSeq(_) => match algorithm {
-            Algorithm::HS256 => Ok(()),
-            Algorithm::HS384 => Ok(()),
-            Algorithm::HS512 => Ok(()),
+        EncodingKey::OctetSeq(s) => match algorithm {
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => check_hmac_key_len(algorithm, s),
             _ => Err(ErrorKind::InvalidAlgorithm.into()),
         },
         EncodingKey::Rsa(_) => match algorithm {
@@ -62,11 +83,10 @@ pub fn validate_matching_key(key: &EncodingKey, algorithm: Algorithm) -> Result<
             | Algorithm::RS512 => Ok(()),
             _ => Err(ErrorKind::InvalidAlgorithm.into()),
         },
-        // EncodingKey::EcPkcs8(_)
-        //     => match algorithm {
-        //         Algorithm::ES256 | Algorithm::ES384 => Ok(()),
-        //         _ => Err(ErrorKind::InvalidAlgorithm.into())
-        //     }
+        EncodingKey::EcPkcs8(_) => match algorithm {
+            Algorithm::ES256 | Algorithm::ES384 | Algorithm::ES512 => Ok(()),
+            _ => Err(ErrorKind::InvalidAlgorithm.into()),
+        },
     }
 }
 
@@ -96,13 +116,12 @@ pub fn sign(message: &str, key: &EncodingKey, algorithm: Algorithm) -> Result<Op
             | Algorithm::PS512 => rsa::sign(algorithm, k, message).map(Some),
             _ => Err(ErrorKind::InvalidAlgorithm.into()),
         },
-        // EncodingKey::EcPkcs8(k)
-        //     => match algorithm {
-        //         Algorithm::ES256 | Algorithm::ES384 => {
-        //             ecdsa::sign_pkcs8(ecdsa::alg_to_ec_signing(algorithm), k, message)
-        //         },
-        //         _ => Err(ErrorKind::InvalidAlgorithm.into())
-        //     }
+        EncodingKey::EcPkcs8(k) => match algorithm {
+            Algorithm::ES256 | Algorithm::ES384 | Algorithm::ES512 => {
+                ecdsa::sign_pkcs8(algorithm, k, message).map(Some)
+            }
+            _ => Err(ErrorKind::InvalidAlgorithm.into()),
+        },
     }
 }
 
@@ -124,6 +143,7 @@ pub fn verify(
         DecodingKey::None => Err(new_error(ErrorKind::InvalidSignature)),
         DecodingKey::OctetSeq(s) => match algorithm {
             Algorithm::HS256 => {
+                check_hmac_key_len(algorithm, s)?;
                 let mut mac = HmacSha256::new_from_slice(s).unwrap();
                 mac.update(message.as_bytes());
                 let decoded_sig = b64_decode(signature)
@@ -131,6 +151,7 @@ pub fn verify(
                 Ok(mac.verify_slice(&decoded_sig[..]).is_ok())
             }
             Algorithm::HS384 => {
+                check_hmac_key_len(algorithm, s)?;
                 let mut mac = HmacSha384::new_from_slice(s).unwrap();
                 mac.update(message.as_bytes());
                 let decoded_sig = b64_decode(signature)
@@ -138,6 +159,7 @@ pub fn verify(
                 Ok(mac.verify_slice(&decoded_sig[..]).is_ok())
             }
             Algorithm::HS512 => {
+                check_hmac_key_len(algorithm, s)?;
                 let mut mac = HmacSha512::new_from_slice(s).unwrap();
                 mac.update(message.as_bytes());
                 let decoded_sig = b64_decode(signature)
@@ -155,5 +177,11 @@ pub fn verify(
             | Algorithm::PS512 => rsa::verify(algorithm, signature, message, k),
             _ => Err(ErrorKind::InvalidAlgorithm.into()),
         },
+        DecodingKey::Ec(k) => match algorithm {
+            Algorithm::ES256 | Algorithm::ES384 | Algorithm::ES512 => {
+                ecdsa::verify(algorithm, signature, message, k)
+            }
+            _ => Err(ErrorKind::InvalidAlgorithm.into()),
+        },
     }
 }
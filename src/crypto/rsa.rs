@@ -0,0 +1,57 @@
+use openssl::hash::MessageDigest;
+use openssl::pkey::{PKey, Private, Public};
+use openssl::rsa::{Padding, Rsa};
+use openssl::sign::{RsaPssSaltlen, Signer, Verifier};
+
+use crate::errors::{new_error, ErrorKind, Result};
+use crate::serialization::{b64_decode, b64_encode};
+use crate::Algorithm;
+
+/// Returns the message digest used for a given RSA `Algorithm`; whether it is a PSS variant
+/// (which needs salt-length/padding set on the `Signer`) is answered by `is_pss`.
+fn digest(algorithm: Algorithm) -> MessageDigest {
+    match algorithm {
+        Algorithm::RS256 | Algorithm::PS256 => MessageDigest::sha256(),
+        Algorithm::RS384 | Algorithm::PS384 => MessageDigest::sha384(),
+        Algorithm::RS512 | Algorithm::PS512 => MessageDigest::sha512(),
+        _ => unreachable!("digest() called with a non-RSA algorithm"),
+    }
+}
+
+fn is_pss(algorithm: Algorithm) -> bool {
+    matches!(algorithm, Algorithm::PS256 | Algorithm::PS384 | Algorithm::PS512)
+}
+
+/// Signs `message` with an RSA private key, returning the base64url-encoded JWS signature.
+pub(crate) fn sign(algorithm: Algorithm, key: &Rsa<Private>, message: &str) -> Result<String> {
+    let pkey = PKey::from_rsa(key.clone()).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    let mut signer = Signer::new(digest(algorithm), &pkey).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+
+    if is_pss(algorithm) {
+        signer.set_rsa_padding(Padding::PKCS1_PSS).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+        signer
+            .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
+            .map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    }
+
+    signer.update(message.as_bytes()).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    let signature = signer.sign_to_vec().map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    Ok(b64_encode(&signature))
+}
+
+/// Verifies a JWS signature against an RSA public key.
+pub(crate) fn verify(algorithm: Algorithm, signature: &str, message: &str, key: &Rsa<Public>) -> Result<bool> {
+    let decoded_sig = b64_decode(signature).map_err(|_| new_error(ErrorKind::InvalidSignature))?;
+    let pkey = PKey::from_rsa(key.clone()).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    let mut verifier = Verifier::new(digest(algorithm), &pkey).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+
+    if is_pss(algorithm) {
+        verifier.set_rsa_padding(Padding::PKCS1_PSS).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+        verifier
+            .set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH)
+            .map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    }
+
+    verifier.update(message.as_bytes()).map_err(|_| new_error(ErrorKind::InvalidRsaKey))?;
+    Ok(verifier.verify(&decoded_sig).unwrap_or(false))
+}
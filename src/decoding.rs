@@ -0,0 +1,83 @@
+use std::fs;
+use std::path::Path;
+
+use openssl::ec::EcKey;
+use openssl::pkey::Public;
+use openssl::rsa::Rsa;
+
+use crate::errors::{new_error, ErrorKind, Result};
+use crate::pem;
+
+/// The key material used to verify a JWT signature, in whatever form the chosen algorithm
+/// family expects.
+#[derive(Clone)]
+pub enum DecodingKey {
+    /// No key, used only with [`crate::Algorithm::None`]
+    None,
+    /// A raw secret, used with the HMAC family (HS256/HS384/HS512)
+    OctetSeq(Vec<u8>),
+    /// An RSA public key, used with the RS*/PS* families
+    Rsa(Rsa<Public>),
+    /// An EC public key, used with the ES* family
+    Ec(EcKey<Public>),
+}
+
+impl DecodingKey {
+    /// Builds a `DecodingKey` for the HMAC family from a raw secret.
+    pub fn from_secret(secret: &[u8]) -> Self {
+        DecodingKey::OctetSeq(secret.to_vec())
+    }
+
+    /// Builds a `DecodingKey` for the RSA family from a PEM-encoded public key.
+    pub fn from_rsa_pem(key: &[u8]) -> Result<Self> {
+        Rsa::public_key_from_pem(key)
+            .or_else(|_| Rsa::public_key_from_pem_pkcs1(key))
+            .map(DecodingKey::Rsa)
+            .map_err(|_| new_error(ErrorKind::InvalidRsaKey))
+    }
+
+    /// Builds a `DecodingKey` for the RSA family from a DER-encoded public key.
+    pub fn from_rsa_der(key: &[u8]) -> Result<Self> {
+        Rsa::public_key_from_der(key).map(DecodingKey::Rsa).map_err(|_| new_error(ErrorKind::InvalidRsaKey))
+    }
+
+    /// Builds a `DecodingKey` for the EC family from a PEM-encoded public key.
+    pub fn from_ec_pem(key: &[u8]) -> Result<Self> {
+        EcKey::public_key_from_pem(key).map(DecodingKey::Ec).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))
+    }
+
+    /// Builds a `DecodingKey` for the EC family from a DER-encoded public key.
+    pub fn from_ec_der(key: &[u8]) -> Result<Self> {
+        EcKey::public_key_from_der(key).map(DecodingKey::Ec).map_err(|_| new_error(ErrorKind::InvalidEcdsaKey))
+    }
+
+    /// Reads a PEM-encoded RSA public key from `path`.
+    pub fn from_rsa_pem_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let pem = fs::read(path).map_err(|_| new_error(ErrorKind::Io))?;
+        match pem::label(&pem) {
+            Some("PUBLIC KEY") | Some("RSA PUBLIC KEY") => Self::from_rsa_pem(&pem),
+            _ => Err(new_error(ErrorKind::InvalidKeyFormat)),
+        }
+    }
+
+    /// Reads a DER-encoded RSA public key from `path`.
+    pub fn from_rsa_der_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let der = fs::read(path).map_err(|_| new_error(ErrorKind::Io))?;
+        Self::from_rsa_der(&der)
+    }
+
+    /// Reads a PEM-encoded EC public key from `path`.
+    pub fn from_ec_pem_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let pem = fs::read(path).map_err(|_| new_error(ErrorKind::Io))?;
+        match pem::label(&pem) {
+            Some("PUBLIC KEY") | Some("EC PUBLIC KEY") => Self::from_ec_pem(&pem),
+            _ => Err(new_error(ErrorKind::InvalidKeyFormat)),
+        }
+    }
+
+    /// Reads a DER-encoded EC public key from `path`.
+    pub fn from_ec_der_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let der = fs::read(path).map_err(|_| new_error(ErrorKind::Io))?;
+        Self::from_ec_der(&der)
+    }
+}
@@ -0,0 +1,71 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+/// A crate-private helper for building an [`Error`] from an [`ErrorKind`].
+pub(crate) fn new_error(kind: ErrorKind) -> Error {
+    Error { kind }
+}
+
+/// A crate result type using our own [`Error`].
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// The kinds of errors that can occur while encoding/decoding JWTs.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The token is invalid (malformed, wrong number of parts, etc.)
+    InvalidToken,
+    /// The signature does not match
+    InvalidSignature,
+    /// The given key doesn't support the requested algorithm
+    InvalidAlgorithm,
+    /// The RSA key could not be parsed
+    InvalidRsaKey,
+    /// The ECDSA key could not be parsed
+    InvalidEcdsaKey,
+    /// The key's PEM/DER encoding doesn't match the label expected for the algorithm family
+    InvalidKeyFormat,
+    /// The key is shorter than the algorithm requires (see RFC 7518 §3.2 for HMAC)
+    InvalidKeyLength,
+    /// Base64 decoding of a token part failed
+    Base64,
+    /// Reading a key file from disk failed
+    Io,
+}
+
+/// An error that occurred while encoding/decoding a JWT.
+#[derive(Debug)]
+pub struct Error {
+    kind: ErrorKind,
+}
+
+impl Error {
+    /// Returns the kind of error that occurred.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+}
+
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Self {
+        Error { kind }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ErrorKind::InvalidToken => write!(f, "invalid token"),
+            ErrorKind::InvalidSignature => write!(f, "invalid signature"),
+            ErrorKind::InvalidAlgorithm => write!(f, "the algorithm isn't supported by the given key"),
+            ErrorKind::InvalidRsaKey => write!(f, "invalid RSA key"),
+            ErrorKind::InvalidEcdsaKey => write!(f, "invalid ECDSA key"),
+            ErrorKind::InvalidKeyFormat => write!(f, "the key's encoding doesn't match the requested algorithm"),
+            ErrorKind::InvalidKeyLength => write!(f, "the key is too short for the requested algorithm"),
+            ErrorKind::Base64 => write!(f, "invalid base64"),
+            ErrorKind::Io => write!(f, "failed to read key from disk"),
+        }
+    }
+}
+
+impl StdError for Error {}
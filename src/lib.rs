@@ -0,0 +1,14 @@
+//! Create and verify JWTs (JSON Web Tokens), backed by `openssl` for the RSA/EC primitives and the
+//! `hmac`/`sha2` crates for HMAC.
+
+mod algorithms;
+pub mod crypto;
+mod decoding;
+mod encoding;
+pub mod errors;
+mod pem;
+mod serialization;
+
+pub use algorithms::Algorithm;
+pub use decoding::DecodingKey;
+pub use encoding::EncodingKey;
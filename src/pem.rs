@@ -0,0 +1,9 @@
+//! Tiny helper for sniffing the label out of a PEM block without pulling in a full parser.
+
+/// Returns the label between `-----BEGIN ` and `-----` in a PEM-encoded blob, if any.
+pub(crate) fn label(pem: &[u8]) -> Option<&str> {
+    let text = std::str::from_utf8(pem).ok()?;
+    let start = text.find("-----BEGIN ")? + "-----BEGIN ".len();
+    let end = start + text[start..].find("-----")?;
+    Some(&text[start..end])
+}
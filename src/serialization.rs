@@ -0,0 +1,14 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+
+use crate::errors::{new_error, ErrorKind, Result};
+
+/// Encodes `input` as unpadded base64url, as used throughout JWTs.
+pub(crate) fn b64_encode(input: &[u8]) -> String {
+    URL_SAFE_NO_PAD.encode(input)
+}
+
+/// Decodes an unpadded base64url string, as used throughout JWTs.
+pub(crate) fn b64_decode(input: &str) -> Result<Vec<u8>> {
+    URL_SAFE_NO_PAD.decode(input).map_err(|_| new_error(ErrorKind::Base64))
+}